@@ -0,0 +1,60 @@
+use embedded_hal::digital::v2::OutputPin as EHOutputPin;
+use rppal::spi::Spi;
+
+/// Adapter to make `rppal::gpio::OutputPin` compatible with embedded-hal.
+pub struct PinAdapter(pub rppal::gpio::OutputPin);
+
+impl EHOutputPin for PinAdapter {
+    type Error = rppal::gpio::Error;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.set_low();
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.set_high();
+        Ok(())
+    }
+}
+
+/// Adapter to make `rppal::Spi` compatible with embedded-hal.
+pub struct SpiAdapter(pub Spi);
+
+impl embedded_hal::blocking::spi::Write<u8> for SpiAdapter {
+    type Error = rppal::spi::Error;
+
+    fn write(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(data)?;
+        Ok(())
+    }
+}
+
+impl embedded_hal::blocking::spi::Transfer<u8> for SpiAdapter {
+    type Error = rppal::spi::Error;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        let write_buf = words.to_vec();
+        self.0.transfer(words, &write_buf)?;
+        Ok(words)
+    }
+}
+
+/// Adapter providing a blocking embedded-hal delay via `std::thread::sleep`,
+/// for drivers (e.g. ili9341) that take their own `DelayMs` implementation.
+pub struct StdDelay;
+
+impl embedded_hal::blocking::delay::DelayMs<u8> for StdDelay {
+    fn delay_ms(&mut self, ms: u8) {
+        std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+    }
+}
+
+// `DelayMs<N>` is implemented per integer width, so drivers that ask for a
+// wider type (e.g. ili9341's `DelayMs<u16>`) need their own impl here even
+// though it does exactly the same thing.
+impl embedded_hal::blocking::delay::DelayMs<u16> for StdDelay {
+    fn delay_ms(&mut self, ms: u16) {
+        std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+    }
+}
@@ -0,0 +1,113 @@
+use display_interface_spi::SPIInterface;
+use embedded_graphics::{pixelcolor::raw::RawU16, pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use ili9341::{DisplaySize240x320, Ili9341, Orientation};
+
+use crate::adapter::{PinAdapter, SpiAdapter, StdDelay};
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+type Interface = SPIInterface<SpiAdapter, PinAdapter, PinAdapter>;
+
+/// ILI9341 colour TFT panel (240x320), driven over SPI.
+///
+/// Unlike the OLED backends this controller writes straight to its own
+/// display RAM on every draw call, so there's no in-memory framebuffer to
+/// push out; [`Panel::flush`] is a no-op.
+///
+/// We implement `DrawTarget` ourselves on top of the driver's raw
+/// `draw_raw_iter` rather than enabling its `graphics` feature: that feature
+/// pulls in `embedded-graphics-core 0.3`, a whole major version behind the
+/// `embedded-graphics 0.8` the rest of this crate (and its other backends)
+/// depend on, so the two `DrawTarget` traits wouldn't even be the same
+/// trait.
+pub struct Ili9341Panel {
+    display: Ili9341<Interface, PinAdapter>,
+}
+
+impl Ili9341Panel {
+    /// Build and initialise an ILI9341 panel over SPI.
+    ///
+    /// The driver owns `reset` directly and pulses it itself during `new`.
+    pub fn new_spi(
+        spi: SpiAdapter,
+        dc: PinAdapter,
+        cs: PinAdapter,
+        reset: PinAdapter,
+    ) -> Result<Self, PanelError> {
+        let interface = SPIInterface::new(spi, dc, cs);
+        let display = Ili9341::new(
+            interface,
+            reset,
+            &mut StdDelay,
+            Orientation::Landscape,
+            DisplaySize240x320,
+        )?;
+
+        let mut panel = Self { display };
+        panel.clear(Rgb565::BLACK)?;
+        Ok(panel)
+    }
+}
+
+impl DrawTarget for Ili9341Panel {
+    type Color = Rgb565;
+    type Error = PanelError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+        for Pixel(point, color) in pixels.into_iter().filter(|Pixel(p, _)| bb.contains(*p)) {
+            let x = point.x as u16;
+            let y = point.y as u16;
+            self.display
+                .draw_raw_iter(x, y, x, y, core::iter::once(RawU16::from(color).into_inner()))?;
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let drawable_area = area.intersection(&self.bounding_box());
+        let Some(bottom_right) = drawable_area.bottom_right() else {
+            return Ok(());
+        };
+
+        let x0 = drawable_area.top_left.x as u16;
+        let y0 = drawable_area.top_left.y as u16;
+        let x1 = bottom_right.x as u16;
+        let y1 = bottom_right.y as u16;
+
+        self.display.draw_raw_iter(
+            x0,
+            y0,
+            x1,
+            y1,
+            area.points()
+                .zip(colors)
+                .filter(|(point, _)| drawable_area.contains(*point))
+                .map(|(_, color)| RawU16::from(color).into_inner()),
+        )?;
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.bounding_box();
+        self.fill_contiguous(&area, core::iter::repeat(color))
+    }
+}
+
+impl OriginDimensions for Ili9341Panel {
+    fn size(&self) -> Size {
+        Size::new(self.display.width() as u32, self.display.height() as u32)
+    }
+}
+
+impl Panel for Ili9341Panel {
+    fn flush(&mut self) -> Result<(), PanelError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod ili9341;
+pub mod ssd1306;
+pub mod ssd1327;
+pub mod ssd1351;
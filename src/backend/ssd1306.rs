@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use display_interface_spi::SPIInterface;
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+use embedded_hal::digital::v2::OutputPin;
+use rppal::i2c::I2c;
+use ssd1306::{mode::BufferedGraphicsMode, prelude::*, size::DisplaySize128x64, I2CDisplayInterface, Ssd1306};
+
+use crate::adapter::{PinAdapter, SpiAdapter};
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+type SpiInterface = SPIInterface<SpiAdapter, PinAdapter, PinAdapter>;
+type I2cInterface = ssd1306::prelude::I2CInterface<I2c>;
+
+type SpiDisplay = Ssd1306<SpiInterface, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>;
+type I2cDisplay = Ssd1306<I2cInterface, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>;
+
+enum Driver {
+    Spi(SpiDisplay),
+    I2c(I2cDisplay),
+}
+
+/// SSD1306 monochrome OLED panel (128x64), driven over SPI or I2C.
+pub struct Ssd1306Panel {
+    driver: Driver,
+    // Kept alive for the life of the panel; dropping it would float the pin.
+    #[allow(dead_code)]
+    reset: Option<PinAdapter>,
+}
+
+impl Ssd1306Panel {
+    /// Build and initialise an SSD1306 panel over SPI.
+    pub fn new_spi(
+        spi: SpiAdapter,
+        dc: PinAdapter,
+        cs: PinAdapter,
+        mut reset: PinAdapter,
+    ) -> Result<Self, PanelError> {
+        let interface = SPIInterface::new(spi, dc, cs);
+        let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+            .into_buffered_graphics_mode();
+
+        reset.set_high()?;
+        std::thread::sleep(Duration::from_millis(1));
+        reset.set_low()?;
+        std::thread::sleep(Duration::from_millis(10));
+        reset.set_high()?;
+
+        display.init()?;
+        display.clear(BinaryColor::Off)?;
+
+        Ok(Self {
+            driver: Driver::Spi(display),
+            reset: Some(reset),
+        })
+    }
+
+    /// Build and initialise an SSD1306 panel over I2C at `addr` (typically
+    /// `0x3C`).
+    ///
+    /// Probes the bus with a single-byte read first, so a panel that isn't
+    /// actually wired up fails cleanly with [`PanelError::DisplayAbsent`]
+    /// instead of the driver hanging or panicking during `init`.
+    pub fn new_i2c(mut i2c: I2c, addr: u16) -> Result<Self, PanelError> {
+        i2c.set_slave_address(addr)?;
+        let mut probe = [0u8];
+        if i2c.read(&mut probe).is_err() {
+            return Err(PanelError::DisplayAbsent { addr });
+        }
+
+        // I2C addressing here is always 7-bit, so this always fits.
+        let interface = I2CDisplayInterface::new_custom_address(i2c, addr as u8);
+        let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+            .into_buffered_graphics_mode();
+
+        display.init()?;
+        display.clear(BinaryColor::Off)?;
+
+        Ok(Self {
+            driver: Driver::I2c(display),
+            reset: None,
+        })
+    }
+}
+
+impl DrawTarget for Ssd1306Panel {
+    type Color = BinaryColor;
+    type Error = PanelError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        match &mut self.driver {
+            Driver::Spi(d) => d.draw_iter(pixels)?,
+            Driver::I2c(d) => d.draw_iter(pixels)?,
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        match &mut self.driver {
+            Driver::Spi(d) => d.fill_contiguous(area, colors)?,
+            Driver::I2c(d) => d.fill_contiguous(area, colors)?,
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        match &mut self.driver {
+            Driver::Spi(d) => d.clear(color)?,
+            Driver::I2c(d) => d.clear(color)?,
+        }
+        Ok(())
+    }
+}
+
+impl OriginDimensions for Ssd1306Panel {
+    fn size(&self) -> Size {
+        match &self.driver {
+            Driver::Spi(d) => d.size(),
+            Driver::I2c(d) => d.size(),
+        }
+    }
+}
+
+impl Panel for Ssd1306Panel {
+    fn flush(&mut self) -> Result<(), PanelError> {
+        match &mut self.driver {
+            Driver::Spi(d) => d.flush()?,
+            Driver::I2c(d) => d.flush()?,
+        }
+        Ok(())
+    }
+}
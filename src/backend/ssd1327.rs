@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use display_interface_spi::SPIInterface;
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_graphics_06::{
+    drawable::Pixel as OldPixel, pixelcolor::Gray4, pixelcolor::GrayColor,
+    DrawTarget as OldDrawTarget,
+};
+use ssd1327::display::Ssd1327;
+
+use crate::adapter::{PinAdapter, SpiAdapter};
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+type Interface = SPIInterface<SpiAdapter, PinAdapter, PinAdapter>;
+
+const WIDTH: u32 = 128;
+const HEIGHT: u32 = 128;
+
+/// SSD1327 colour-capable greyscale OLED panel (128x128), driven over SPI.
+///
+/// The controller is natively 4-bit greyscale; we expose it through
+/// `Rgb565` like the other colour backends so drawing code doesn't need to
+/// special-case it, and quantise each pixel down to greyscale on write.
+///
+/// `ssd1327` 0.1 predates `embedded-graphics` 0.8's `draw_iter`-based
+/// `DrawTarget` and still implements the old per-pixel `DrawTarget<Gray4>`
+/// from `embedded-graphics` 0.6 (there's no newer release), so we draw
+/// through that directly, converting each pixel ourselves, rather than
+/// depending on two incompatible `DrawTarget` traits lining up.
+pub struct Ssd1327Panel {
+    display: Ssd1327<Interface>,
+    // Kept alive for the life of the panel; dropping it would float the pin.
+    #[allow(dead_code)]
+    reset: PinAdapter,
+}
+
+impl Ssd1327Panel {
+    /// Build and initialise an SSD1327 panel over SPI.
+    pub fn new_spi(
+        spi: SpiAdapter,
+        dc: PinAdapter,
+        cs: PinAdapter,
+        mut reset: PinAdapter,
+    ) -> Result<Self, PanelError> {
+        let interface = SPIInterface::new(spi, dc, cs);
+        let mut display = Ssd1327::new(interface);
+
+        reset.set_high()?;
+        std::thread::sleep(Duration::from_millis(1));
+        reset.set_low()?;
+        std::thread::sleep(Duration::from_millis(10));
+        reset.set_high()?;
+
+        display.init()?;
+        OldDrawTarget::clear(&mut display, Gray4::BLACK)?;
+        display.flush()?;
+
+        Ok(Self { display, reset })
+    }
+}
+
+/// Quantise an RGB565 colour down to 4-bit greyscale using the standard
+/// luma weighting, rescaled from each channel's own bit depth.
+fn rgb565_to_gray4(color: Rgb565) -> Gray4 {
+    let r = u32::from(color.r()) * 255 / 31;
+    let g = u32::from(color.g()) * 255 / 63;
+    let b = u32::from(color.b()) * 255 / 31;
+    let luma8 = (r * 299 + g * 587 + b * 114) / 1000;
+    Gray4::new((luma8 >> 4) as u8)
+}
+
+impl DrawTarget for Ssd1327Panel {
+    type Color = Rgb565;
+    type Error = PanelError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+        for Pixel(point, color) in pixels.into_iter().filter(|Pixel(p, _)| bb.contains(*p)) {
+            let old_point = embedded_graphics_06::geometry::Point::new(point.x, point.y);
+            self.display
+                .draw_pixel(OldPixel(old_point, rgb565_to_gray4(color)))?;
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = Rectangle::new(Point::zero(), self.size());
+        self.fill_contiguous(&area, std::iter::repeat(color))
+    }
+}
+
+impl OriginDimensions for Ssd1327Panel {
+    fn size(&self) -> Size {
+        Size::new(WIDTH, HEIGHT)
+    }
+}
+
+impl Panel for Ssd1327Panel {
+    fn flush(&mut self) -> Result<(), PanelError> {
+        self.display.flush()?;
+        Ok(())
+    }
+}
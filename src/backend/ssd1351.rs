@@ -0,0 +1,108 @@
+use display_interface::DisplayError;
+use embedded_graphics::{pixelcolor::raw::RawU16, pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use ssd1351::{
+    builder::Builder, interface::SpiInterface, mode::GraphicsMode, properties::DisplayRotation,
+};
+
+use crate::adapter::{PinAdapter, SpiAdapter, StdDelay};
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+type Interface = SpiInterface<SpiAdapter, PinAdapter>;
+
+/// Map one of this driver's unit `Err(())`s (it doesn't carry any detail) to
+/// our own error type.
+fn driver_err(_: ()) -> PanelError {
+    PanelError::Display(DisplayError::BusWriteError)
+}
+
+/// SSD1351 colour OLED panel (128x128), driven over SPI.
+///
+/// We draw through the driver's own `set_pixel`, sidestepping its
+/// `DrawTarget` impl entirely: that impl only exists behind the `graphics`
+/// feature, which pulls in `embedded-graphics-core 0.3` - a whole major
+/// version behind the `embedded-graphics 0.8` the rest of this crate (and
+/// its other backends) depend on, so the `Pixel`/`Point` types on each side
+/// wouldn't even be the same type.
+///
+/// Unlike the other SPI backends, this driver's own `SpiInterface` only
+/// wraps the SPI peripheral and the D/C pin; it doesn't do software chip
+/// select like `display-interface-spi` does, so `cs` is kept alive here
+/// purely because the hardware line still needs to be held for the life of
+/// the panel.
+pub struct Ssd1351Panel {
+    display: GraphicsMode<Interface>,
+    #[allow(dead_code)]
+    cs: PinAdapter,
+    #[allow(dead_code)]
+    reset: PinAdapter,
+}
+
+impl Ssd1351Panel {
+    /// Build and initialise an SSD1351 panel over SPI.
+    pub fn new_spi(
+        spi: SpiAdapter,
+        dc: PinAdapter,
+        cs: PinAdapter,
+        mut reset: PinAdapter,
+    ) -> Result<Self, PanelError> {
+        let mut display: GraphicsMode<Interface> = Builder::new()
+            .with_rotation(DisplayRotation::Rotate0)
+            .connect_spi(spi, dc)
+            .into();
+
+        display.reset(&mut reset, &mut StdDelay)?;
+        display.init().map_err(driver_err)?;
+        display.clear();
+
+        Ok(Self { display, cs, reset })
+    }
+}
+
+impl DrawTarget for Ssd1351Panel {
+    type Color = Rgb565;
+    type Error = PanelError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+        for Pixel(point, color) in pixels.into_iter().filter(|Pixel(p, _)| bb.contains(*p)) {
+            self.display
+                .set_pixel(point.x as u32, point.y as u32, RawU16::from(color).into_inner());
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.bounding_box();
+        self.fill_contiguous(&area, std::iter::repeat(color))
+    }
+}
+
+impl OriginDimensions for Ssd1351Panel {
+    fn size(&self) -> Size {
+        let (w, h) = self.display.get_dimensions();
+        Size::new(u32::from(w), u32::from(h))
+    }
+}
+
+impl Panel for Ssd1351Panel {
+    // Like the ILI9341 backend, every `set_pixel` already writes straight to
+    // the display's own RAM, so there's no in-memory framebuffer to push out.
+    fn flush(&mut self) -> Result<(), PanelError> {
+        Ok(())
+    }
+}
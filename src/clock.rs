@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    text::{Baseline, Text},
+};
+use time::format_description::FormatItem;
+use time::{format_description, OffsetDateTime};
+use time_tz::{OffsetDateTimeExt, Tz};
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+use crate::ui::{Button, Scene};
+
+/// A menu scene that shows the current wall-clock time and date, converted
+/// from system UTC into `tz`.
+pub struct ClockScene<C> {
+    tz: &'static Tz,
+    fg: C,
+    bg: C,
+    time_fmt: Vec<FormatItem<'static>>,
+    date_fmt: Vec<FormatItem<'static>>,
+    last_min: Option<u8>,
+}
+
+impl<C: PixelColor> ClockScene<C> {
+    pub fn new(tz: &'static Tz, fg: C, bg: C) -> Result<Self, PanelError> {
+        let time_fmt = format_description::parse_borrowed::<1>("[hour]:[minute]")
+            .map_err(|e| PanelError::Time(e.to_string()))?;
+        let date_fmt = format_description::parse_borrowed::<1>("[year]-[month]-[day]")
+            .map_err(|e| PanelError::Time(e.to_string()))?;
+
+        Ok(Self {
+            tz,
+            fg,
+            bg,
+            time_fmt,
+            date_fmt,
+            last_min: None,
+        })
+    }
+}
+
+impl<P: Panel> Scene<P> for ClockScene<P::Color> {
+    fn name(&self) -> &str {
+        "Clock"
+    }
+
+    fn render(&mut self, target: &mut P) -> Result<(), PanelError> {
+        let now = OffsetDateTime::now_utc().to_timezone(self.tz);
+        self.last_min = Some(now.minute());
+
+        let time_str = now
+            .format(&self.time_fmt)
+            .map_err(|e| PanelError::Time(e.to_string()))?;
+        let date_str = now
+            .format(&self.date_fmt)
+            .map_err(|e| PanelError::Time(e.to_string()))?;
+
+        target.clear(self.bg)?;
+        let style = MonoTextStyle::new(&FONT_6X10, self.fg);
+        Text::with_baseline(&time_str, Point::new(5, 5), style, Baseline::Top).draw(target)?;
+        Text::with_baseline(&date_str, Point::new(5, 20), style, Baseline::Top).draw(target)?;
+        Ok(())
+    }
+
+    fn on_input(&mut self, _button: Button) {}
+
+    fn poll_interval(&self) -> Option<Duration> {
+        let now = OffsetDateTime::now_utc();
+        let secs_left_in_minute = 60 - u64::from(now.second());
+        Some(Duration::from_secs(secs_left_in_minute.max(1)))
+    }
+
+    fn poll(&mut self) -> bool {
+        let now = OffsetDateTime::now_utc().to_timezone(self.tz);
+        self.last_min != Some(now.minute())
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+/// Error type unifying the failure modes of the various display backends.
+///
+/// Wraps the lower-level errors produced by `display-interface` and by
+/// `rppal`'s GPIO/SPI peripherals so callers only need to handle one error
+/// type regardless of which [`crate::panel::Panel`] backend they're using.
+#[derive(Debug)]
+pub enum PanelError {
+    Display(display_interface::DisplayError),
+    Gpio(rppal::gpio::Error),
+    Spi(rppal::spi::Error),
+    I2c(rppal::i2c::Error),
+    /// A probe against the configured I2C address got no response.
+    DisplayAbsent { addr: u16 },
+    /// The requested `PanelKind`/`Interface` combination isn't wired up.
+    Unsupported(&'static str),
+    /// A GPIO line request/read failed (button input).
+    Gpiocdev(String),
+    /// A BMP or raw image blob couldn't be decoded.
+    ImageDecode(&'static str),
+    /// A `time` formatting or timezone conversion failed.
+    Time(String),
+    /// The MAX6675 reported its thermocouple input as open-circuit.
+    ThermocoupleOpen,
+}
+
+impl fmt::Display for PanelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PanelError::Display(_) => write!(f, "display error"),
+            PanelError::Gpio(e) => write!(f, "GPIO error: {e}"),
+            PanelError::Spi(e) => write!(f, "SPI error: {e}"),
+            PanelError::I2c(e) => write!(f, "I2C error: {e}"),
+            PanelError::DisplayAbsent { addr } => {
+                write!(f, "no response from display at I2C address {addr:#04x}")
+            }
+            PanelError::Unsupported(msg) => write!(f, "unsupported panel configuration: {msg}"),
+            PanelError::Gpiocdev(msg) => write!(f, "GPIO line error: {msg}"),
+            PanelError::ImageDecode(msg) => write!(f, "image decode error: {msg}"),
+            PanelError::Time(msg) => write!(f, "time error: {msg}"),
+            PanelError::ThermocoupleOpen => write!(f, "thermocouple open-circuit"),
+        }
+    }
+}
+
+impl std::error::Error for PanelError {}
+
+impl From<display_interface::DisplayError> for PanelError {
+    fn from(error: display_interface::DisplayError) -> Self {
+        PanelError::Display(error)
+    }
+}
+
+impl From<rppal::gpio::Error> for PanelError {
+    fn from(error: rppal::gpio::Error) -> Self {
+        PanelError::Gpio(error)
+    }
+}
+
+impl From<rppal::spi::Error> for PanelError {
+    fn from(error: rppal::spi::Error) -> Self {
+        PanelError::Spi(error)
+    }
+}
+
+impl From<rppal::i2c::Error> for PanelError {
+    fn from(error: rppal::i2c::Error) -> Self {
+        PanelError::I2c(error)
+    }
+}
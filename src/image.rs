@@ -0,0 +1,52 @@
+use embedded_graphics::{
+    image::{Image, ImageDrawable, ImageRaw},
+    pixelcolor::{raw::RawData, Rgb555, Rgb565, Rgb888},
+    prelude::*,
+};
+use tinybmp::Bmp;
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+/// Draw an already-decoded image (a [`Bmp`] or [`ImageRaw`]) onto `target`
+/// at `pos`. Generic over the image's own colour type, so the same call
+/// works for mono (`BinaryColor`) and colour (`Rgb565`) backends alike.
+pub fn draw_image<P, I>(target: &mut P, image: &I, pos: Point) -> Result<(), PanelError>
+where
+    P: Panel,
+    I: ImageDrawable<Color = P::Color>,
+{
+    Image::new(image, pos).draw(target)
+}
+
+/// Parse a `.bmp` file's bytes into a drawable image.
+pub fn load_bmp<C>(data: &[u8]) -> Result<Bmp<'_, C>, PanelError>
+where
+    C: PixelColor + From<Rgb555> + From<Rgb565> + From<Rgb888>,
+{
+    Bmp::from_slice(data).map_err(|_| PanelError::ImageDecode("invalid BMP data"))
+}
+
+/// Wrap a raw, pre-packed framebuffer blob (e.g. `include_bytes!("star.raw")`)
+/// as a drawable image of the given `size` and colour format `C`.
+///
+/// Validates `data`'s length against `size` and `C`'s bits-per-pixel, since
+/// `ImageRaw` itself only takes a width and infers height from the data -
+/// a blob that doesn't actually match the claimed `size` would otherwise be
+/// accepted silently.
+pub fn load_raw<C>(data: &[u8], size: Size) -> Result<ImageRaw<'_, C>, PanelError>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+{
+    let bits_per_row = size.width as usize * <C::Raw as RawData>::BITS_PER_PIXEL;
+    let bytes_per_row = bits_per_row.div_ceil(8);
+    let expected_len = bytes_per_row * size.height as usize;
+
+    if data.len() != expected_len {
+        return Err(PanelError::ImageDecode(
+            "raw image data length doesn't match the given size",
+        ));
+    }
+
+    Ok(ImageRaw::new(data, size.width))
+}
@@ -0,0 +1,21 @@
+use rppal::gpio::OutputPin;
+use rppal::i2c::I2c;
+use rppal::spi::Spi;
+
+/// How a panel is wired to the host.
+///
+/// [`crate::panel::PanelBuilder`] matches on this to pick the right
+/// `display-interface` implementation and init sequence, so the same
+/// drawing code works unchanged whether a panel is on SPI or I2C.
+pub enum Interface {
+    Spi {
+        bus: Spi,
+        dc: OutputPin,
+        cs: OutputPin,
+        reset: Box<OutputPin>,
+    },
+    I2c {
+        bus: I2c,
+        addr: u16,
+    },
+}
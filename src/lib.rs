@@ -0,0 +1,20 @@
+//! Controller-agnostic display library for Raspberry Pi panels.
+//!
+//! Wraps the SPI/GPIO plumbing for a handful of common display controllers
+//! behind a single [`panel::Panel`] trait, so drawing code written against
+//! `embedded_graphics` doesn't need to know (or change based on) which
+//! controller is actually wired up.
+
+pub mod adapter;
+pub mod backend;
+pub mod clock;
+pub mod error;
+pub mod image;
+pub mod interface;
+pub mod panel;
+pub mod reflow;
+pub mod ui;
+
+pub use error::PanelError;
+pub use interface::Interface;
+pub use panel::{AnyPanel, Panel, PanelBuilder, PanelKind};
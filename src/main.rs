@@ -1,167 +1,133 @@
+use displaytest::clock::ClockScene;
+use displaytest::panel::{AnyPanel, PanelBuilder, PanelKind};
+use displaytest::ui::{Button, ButtonPins, ButtonReader, Menu, Scene};
+use displaytest::{Interface, Panel, PanelError};
+use time_tz::timezones::db::europe::BERLIN;
 use embedded_graphics::{
     mono_font::{ascii::FONT_6X10, MonoTextStyle},
-    pixelcolor::BinaryColor,
+    pixelcolor::{BinaryColor, PixelColor},
     prelude::*,
     primitives::{Circle, PrimitiveStyle, Rectangle, Triangle},
     text::{Baseline, Text},
-    Drawable,
 };
-use embedded_hal::digital::v2::OutputPin as EHOutputPin;
 use rppal::gpio::Gpio;
 use rppal::spi::{Bus, Mode, SlaveSelect, Spi};
-use ssd1306::{prelude::*, size::DisplaySize128x64, Ssd1306};
 use std::error::Error;
 
-// Custom error wrapper to make DisplayError compatible with std::error::Error
-#[derive(Debug)]
-#[allow(dead_code)]
-struct DisplayErrorWrapper(display_interface::DisplayError);
-
-impl std::fmt::Display for DisplayErrorWrapper {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Display error")
-    }
-}
-
-impl Error for DisplayErrorWrapper {}
-
-impl From<display_interface::DisplayError> for DisplayErrorWrapper {
-    fn from(error: display_interface::DisplayError) -> Self {
-        DisplayErrorWrapper(error)
-    }
-}
-
-// Adapter to make rppal::gpio::OutputPin compatible with embedded-hal
-struct PinAdapter(rppal::gpio::OutputPin);
-
-impl EHOutputPin for PinAdapter {
-    type Error = rppal::gpio::Error;
-
-    fn set_low(&mut self) -> Result<(), Self::Error> {
-        self.0.set_low();
-        Ok(())
-    }
-
-    fn set_high(&mut self) -> Result<(), Self::Error> {
-        self.0.set_high();
-        Ok(())
-    }
-}
-
-// Adapter to make rppal::Spi compatible with embedded-hal
-struct SpiAdapter(Spi);
-
-impl embedded_hal::blocking::spi::Write<u8> for SpiAdapter {
-    type Error = rppal::spi::Error;
-
-    fn write(&mut self, data: &[u8]) -> Result<(), Self::Error> {
-        self.0.write(data)?;
-        Ok(())
-    }
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("Initializing OLED display...");
-
-    // Configure SPI
-    let spi = SpiAdapter(Spi::new(Bus::Spi0, SlaveSelect::Ss0, 8_000_000, Mode::Mode0)?);
-
-    // Configure GPIO pins
-    let gpio = Gpio::new()?;
-    let dc_pin = PinAdapter(gpio.get(25)?.into_output());  // Data/Command pin
-    let mut reset_pin = PinAdapter(gpio.get(24)?.into_output());  // Reset pin
-    let cs_pin = PinAdapter(gpio.get(8)?.into_output());  // Chip Select pin
-
-    // Create display interface
-    let interface = display_interface_spi::SPIInterface::new(spi, dc_pin, cs_pin);
-
-    // Create display
-    let mut display = Ssd1306::new(
-        interface,
-        DisplaySize128x64,
-        DisplayRotation::Rotate0,
-    ).into_buffered_graphics_mode();
-
-    // Reset the display
-    reset_pin.set_high()?;
-    std::thread::sleep(std::time::Duration::from_millis(1));
-    reset_pin.set_low()?;
-    std::thread::sleep(std::time::Duration::from_millis(10));
-    reset_pin.set_high()?;
-
-    // Initialize the display (handle error conversion manually)
-    match display.init() {
-        Ok(_) => {},
-        Err(e) => return Err(Box::new(DisplayErrorWrapper(e))),
-    }
-
-    // Clear the display (explicitly provide the BinaryColor parameter)
-    match display.clear(BinaryColor::Off) {
-        Ok(_) => {},
-        Err(e) => return Err(Box::new(DisplayErrorWrapper(e))),
-    }
-
-    // Create styles
-    let text_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
-    let thin_stroke = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-
-    println!("Drawing shapes and text...");
+/// GPIO line offsets for the four menu buttons.
+const BUTTON_PINS: ButtonPins = ButtonPins {
+    chip: "/dev/gpiochip0",
+    up: 5,
+    down: 6,
+    select: 13,
+    back: 19,
+};
 
-    // Draw shapes and handle errors manually
-    let result = Triangle::new(
+/// Draw the demo shapes/text shared by every backend. Generic over the
+/// pixel color so the same code drives mono and color panels alike.
+fn draw_demo<C, P>(display: &mut P, fg: C, label: &str) -> Result<(), P::Error>
+where
+    C: PixelColor,
+    P: Panel<Color = C>,
+{
+    let text_style = MonoTextStyle::new(&FONT_6X10, fg);
+    let thin_stroke = PrimitiveStyle::with_stroke(fg, 1);
+
+    Triangle::new(
         Point::new(16, 16),
         Point::new(16 + 16, 16),
         Point::new(16 + 8, 16 - 8),
     )
-        .into_styled(thin_stroke)
-        .draw(&mut display);
+    .into_styled(thin_stroke)
+    .draw(display)?;
 
-    if let Err(e) = result {
-        return Err(Box::new(DisplayErrorWrapper(e)));
-    }
-
-    let result = Circle::new(Point::new(64, 32), 8)
+    Circle::new(Point::new(64, 32), 8)
         .into_styled(thin_stroke)
-        .draw(&mut display);
-
-    if let Err(e) = result {
-        return Err(Box::new(DisplayErrorWrapper(e)));
-    }
+        .draw(display)?;
 
-    let result = Rectangle::new(Point::new(80, 16), Size::new(32, 32))
+    Rectangle::new(Point::new(80, 16), Size::new(32, 32))
         .into_styled(thin_stroke)
-        .draw(&mut display);
+        .draw(display)?;
 
-    if let Err(e) = result {
-        return Err(Box::new(DisplayErrorWrapper(e)));
-    }
+    Text::with_baseline("Raspberry Pi Zero W", Point::new(5, 5), text_style, Baseline::Top)
+        .draw(display)?;
 
-    // Write text and handle errors manually
-    let result = Text::with_baseline("Raspberry Pi Zero W", Point::new(5, 5), text_style, Baseline::Top)
-        .draw(&mut display);
+    Text::with_baseline(label, Point::new(5, 50), text_style, Baseline::Top).draw(display)?;
 
-    if let Err(e) = result {
-        return Err(Box::new(DisplayErrorWrapper(e)));
-    }
+    Ok(())
+}
 
-    let result = Text::with_baseline("SSD1306 OLED", Point::new(5, 50), text_style, Baseline::Top)
-        .draw(&mut display);
+/// A single menu entry that just shows the shapes demo for its backend.
+struct ShapesScene<C> {
+    name: &'static str,
+    fg: C,
+}
 
-    if let Err(e) = result {
-        return Err(Box::new(DisplayErrorWrapper(e)));
+impl<P: Panel> Scene<P> for ShapesScene<P::Color> {
+    fn name(&self) -> &str {
+        self.name
     }
 
-    // Update the display
-    match display.flush() {
-        Ok(_) => {},
-        Err(e) => return Err(Box::new(DisplayErrorWrapper(e))),
+    fn render(&mut self, target: &mut P) -> Result<(), PanelError> {
+        draw_demo(target, self.fg, self.name)
     }
 
-    println!("Display initialized and pattern drawn successfully!");
-    println!("Press Ctrl+C to exit...");
+    fn on_input(&mut self, _button: Button) {}
+}
 
-    // Keep the program running to maintain the display
-    loop {
-        std::thread::sleep(std::time::Duration::from_secs(1));
+fn run_menu<P>(panel: &mut P, fg: P::Color, bg: P::Color, label: &'static str) -> Result<(), Box<dyn Error>>
+where
+    P: Panel,
+    P::Color: 'static,
+{
+    let scenes: Vec<Box<dyn Scene<P>>> = vec![
+        Box::new(ShapesScene { name: label, fg }),
+        Box::new(ClockScene::new(BERLIN, fg, bg)?),
+    ];
+    let mut menu = Menu::new(scenes, fg, bg);
+    let mut buttons = ButtonReader::new(BUTTON_PINS)?;
+
+    println!("Menu ready, waiting for button presses (Ctrl+C to exit)...");
+    menu.run(panel, &mut buttons)?;
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("Initializing display...");
+
+    let spi = Spi::new(Bus::Spi0, SlaveSelect::Ss0, 8_000_000, Mode::Mode0)?;
+    let gpio = Gpio::new()?;
+    let dc = gpio.get(25)?.into_output(); // Data/Command pin
+    let reset = gpio.get(24)?.into_output(); // Reset pin
+    let cs = gpio.get(8)?.into_output(); // Chip Select pin
+
+    let interface = Interface::Spi {
+        bus: spi,
+        dc,
+        cs,
+        reset: Box::new(reset),
+    };
+    let panel = PanelBuilder::new(PanelKind::Ssd1306, interface).build()?;
+
+    match panel {
+        AnyPanel::Ssd1306(mut p) => run_menu(&mut *p, BinaryColor::On, BinaryColor::Off, "SSD1306 OLED"),
+        AnyPanel::Ssd1351(mut p) => run_menu(
+            &mut p,
+            embedded_graphics::pixelcolor::Rgb565::WHITE,
+            embedded_graphics::pixelcolor::Rgb565::BLACK,
+            "SSD1351 OLED",
+        ),
+        AnyPanel::Ssd1327(mut p) => run_menu(
+            &mut *p,
+            embedded_graphics::pixelcolor::Rgb565::WHITE,
+            embedded_graphics::pixelcolor::Rgb565::BLACK,
+            "SSD1327 OLED",
+        ),
+        AnyPanel::Ili9341(mut p) => run_menu(
+            &mut p,
+            embedded_graphics::pixelcolor::Rgb565::WHITE,
+            embedded_graphics::pixelcolor::Rgb565::BLACK,
+            "ILI9341 TFT",
+        ),
     }
 }
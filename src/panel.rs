@@ -0,0 +1,96 @@
+use embedded_graphics::prelude::*;
+
+use crate::adapter::{PinAdapter, SpiAdapter};
+use crate::backend::{
+    ili9341::Ili9341Panel, ssd1306::Ssd1306Panel, ssd1327::Ssd1327Panel, ssd1351::Ssd1351Panel,
+};
+use crate::error::PanelError;
+use crate::interface::Interface;
+
+/// A display panel that can be drawn to and flushed out to hardware.
+///
+/// Backends implement this on top of `embedded_graphics::DrawTarget`, so the
+/// same drawing code works whether the concrete controller is a monochrome
+/// SSD1306 OLED or a colour SSD1351/SSD1327/ILI9341 panel - `DrawTarget`'s
+/// own `Color: PixelColor` bound is all the abstraction we need over pixel
+/// format.
+pub trait Panel: DrawTarget<Error = PanelError> {
+    /// Push the in-memory framebuffer out to the physical display.
+    fn flush(&mut self) -> Result<(), PanelError>;
+}
+
+/// Which physical controller backs the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelKind {
+    Ssd1306,
+    Ssd1351,
+    Ssd1327,
+    Ili9341,
+}
+
+/// Builds and initialises a [`Panel`] for a given [`PanelKind`], hiding the
+/// controller-specific wiring and reset/init sequence behind one entry
+/// point.
+pub struct PanelBuilder {
+    kind: PanelKind,
+    interface: Interface,
+}
+
+impl PanelBuilder {
+    /// Start building a panel over the given [`Interface`] (SPI or I2C).
+    pub fn new(kind: PanelKind, interface: Interface) -> Self {
+        Self { kind, interface }
+    }
+
+    /// Build and initialise the panel.
+    pub fn build(self) -> Result<AnyPanel, PanelError> {
+        match self.interface {
+            Interface::Spi {
+                bus,
+                dc,
+                cs,
+                reset,
+            } => {
+                let spi = SpiAdapter(bus);
+                let dc = PinAdapter(dc);
+                let cs = PinAdapter(cs);
+                let reset = PinAdapter(*reset);
+
+                Ok(match self.kind {
+                    PanelKind::Ssd1306 => {
+                        AnyPanel::Ssd1306(Box::new(Ssd1306Panel::new_spi(spi, dc, cs, reset)?))
+                    }
+                    PanelKind::Ssd1351 => {
+                        AnyPanel::Ssd1351(Ssd1351Panel::new_spi(spi, dc, cs, reset)?)
+                    }
+                    PanelKind::Ssd1327 => {
+                        AnyPanel::Ssd1327(Box::new(Ssd1327Panel::new_spi(spi, dc, cs, reset)?))
+                    }
+                    PanelKind::Ili9341 => {
+                        AnyPanel::Ili9341(Ili9341Panel::new_spi(spi, dc, cs, reset)?)
+                    }
+                })
+            }
+            Interface::I2c { bus, addr } => match self.kind {
+                PanelKind::Ssd1306 => Ok(AnyPanel::Ssd1306(Box::new(Ssd1306Panel::new_i2c(
+                    bus, addr,
+                )?))),
+                PanelKind::Ssd1351 => Err(PanelError::Unsupported("SSD1351 has no I2C backend")),
+                PanelKind::Ssd1327 => Err(PanelError::Unsupported("SSD1327 has no I2C backend")),
+                PanelKind::Ili9341 => Err(PanelError::Unsupported("ILI9341 has no I2C backend")),
+            },
+        }
+    }
+}
+
+/// A panel resolved to its concrete backend.
+///
+/// Match on this once at startup; each arm still drives its inner panel
+/// through the generic [`Panel`]/`DrawTarget` interface, so the drawing code
+/// itself is written once and shared across every controller.
+pub enum AnyPanel {
+    Ssd1306(Box<Ssd1306Panel>),
+    Ssd1351(Ssd1351Panel),
+    Ssd1327(Box<Ssd1327Panel>),
+    Ili9341(Ili9341Panel),
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    text::{Baseline, Text},
+};
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+use crate::reflow::graph::TempGraph;
+use crate::reflow::heater::Heater;
+use crate::reflow::max6675::Max6675;
+use crate::reflow::pid::Pid;
+
+/// Reflow-oven control loop: reads the thermocouple, steps the PID, drives
+/// the heater, and renders a live readout and temperature graph.
+pub struct ReflowController {
+    sensor: Max6675,
+    heater: Heater,
+    pid: Pid,
+    graph: TempGraph,
+    setpoint: f32,
+    sample_period: Duration,
+}
+
+impl ReflowController {
+    pub fn new(
+        sensor: Max6675,
+        heater: Heater,
+        pid: Pid,
+        graph: TempGraph,
+        setpoint: f32,
+        sample_period: Duration,
+    ) -> Self {
+        Self {
+            sensor,
+            heater,
+            pid,
+            graph,
+            setpoint,
+            sample_period,
+        }
+    }
+
+    /// Run the reflow loop until the thermocouple reports open-circuit or a
+    /// drawing/IO call fails.
+    ///
+    /// One call to `Heater::apply_duty` blocks for the heater's own period,
+    /// so it also paces the sampling rate - no separate sleep is needed.
+    /// `sample_period` is used only as the PID step's `dt`; the loop does not
+    /// measure or enforce it.
+    pub fn run<P: Panel>(&mut self, target: &mut P, fg: P::Color, bg: P::Color) -> Result<(), PanelError> {
+        let dt = self.sample_period.as_secs_f32();
+
+        loop {
+            let temp = match self.sensor.read_celsius() {
+                Ok(temp) => temp,
+                Err(e) => {
+                    // Fail safe: never return with the last commanded duty
+                    // cycle still in effect, e.g. a thermocouple jostled
+                    // loose mid-ramp while the heater was at 100%.
+                    self.heater.apply_duty(0.0)?;
+                    return Err(e);
+                }
+            };
+            let duty = self.pid.step(self.setpoint, temp, dt);
+            self.graph.push_sample(temp);
+
+            let drawn = (|| -> Result<(), PanelError> {
+                target.clear(bg)?;
+                self.graph.draw(target, fg)?;
+
+                let style = MonoTextStyle::new(&FONT_6X10, fg);
+                let readout = format!("{temp:.1}C  duty {duty:.0}%");
+                Text::with_baseline(&readout, Point::new(2, 2), style, Baseline::Top).draw(target)?;
+                target.flush()
+            })();
+            if let Err(e) = drawn {
+                // Same fail safe as a sensor error above.
+                self.heater.apply_duty(0.0)?;
+                return Err(e);
+            }
+
+            self.heater.apply_duty(duty)?;
+        }
+    }
+}
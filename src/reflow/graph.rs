@@ -0,0 +1,54 @@
+use std::collections::VecDeque;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+
+/// A scrolling temperature-vs-time strip chart: one vertical pixel column
+/// per sample, shifting left as new samples arrive.
+pub struct TempGraph {
+    area: Rectangle,
+    min_temp: f32,
+    max_temp: f32,
+    columns: VecDeque<u32>,
+}
+
+impl TempGraph {
+    pub fn new(area: Rectangle, min_temp: f32, max_temp: f32) -> Self {
+        Self {
+            columns: VecDeque::with_capacity(area.size.width as usize),
+            area,
+            min_temp,
+            max_temp,
+        }
+    }
+
+    /// Record a new sample, dropping the oldest column once the chart is
+    /// full width.
+    pub fn push_sample(&mut self, temp: f32) {
+        let frac = ((temp - self.min_temp) / (self.max_temp - self.min_temp)).clamp(0.0, 1.0);
+        let height = (frac * self.area.size.height as f32) as u32;
+
+        if self.columns.len() as u32 >= self.area.size.width {
+            self.columns.pop_front();
+        }
+        self.columns.push_back(height);
+    }
+
+    /// Draw the chart's current columns into `target`.
+    pub fn draw<P: Panel>(&self, target: &mut P, fg: P::Color) -> Result<(), PanelError> {
+        let style = PrimitiveStyle::with_fill(fg);
+        for (i, &height) in self.columns.iter().enumerate() {
+            let x = self.area.top_left.x + i as i32;
+            let y = self.area.top_left.y + (self.area.size.height - height) as i32;
+            Rectangle::new(Point::new(x, y), Size::new(1, height))
+                .into_styled(style)
+                .draw(target)?;
+        }
+        Ok(())
+    }
+}
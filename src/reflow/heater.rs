@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use embedded_hal::digital::v2::OutputPin as EHOutputPin;
+
+use crate::adapter::PinAdapter;
+use crate::error::PanelError;
+
+/// Drives a heater element via software PWM-style duty cycling on a GPIO
+/// pin, one `period` at a time.
+pub struct Heater {
+    pin: PinAdapter,
+    period: Duration,
+}
+
+impl Heater {
+    pub fn new(pin: PinAdapter, period: Duration) -> Self {
+        Self { pin, period }
+    }
+
+    /// Hold the pin high for `duty_percent` of `period`, then low for the
+    /// remainder. Blocks for the whole period, so this doubles as the
+    /// control loop's sample interval.
+    pub fn apply_duty(&mut self, duty_percent: f32) -> Result<(), PanelError> {
+        let duty = duty_percent.clamp(0.0, 100.0) / 100.0;
+        let on_time = self.period.mul_f32(duty);
+        let off_time = self.period.saturating_sub(on_time);
+
+        if !on_time.is_zero() {
+            self.pin.set_high()?;
+            std::thread::sleep(on_time);
+        }
+        if !off_time.is_zero() {
+            self.pin.set_low()?;
+            std::thread::sleep(off_time);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use rppal::spi::Spi;
+
+use crate::error::PanelError;
+
+/// MAX6675 cold-junction-compensated K-type thermocouple-to-digital
+/// converter, read over its own SPI chip-select (separate from the
+/// display's).
+pub struct Max6675 {
+    spi: Spi,
+}
+
+impl Max6675 {
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Clock out the 16-bit read word (CS low for the duration) and decode
+    /// it into degrees Celsius.
+    pub fn read_celsius(&mut self) -> Result<f32, PanelError> {
+        let mut buf = [0u8; 2];
+        self.spi.read(&mut buf)?;
+        decode_word(u16::from_be_bytes(buf))
+    }
+}
+
+/// Decode a MAX6675 16-bit read word into degrees Celsius.
+///
+/// Bit 15 is a dummy, bits 14..=3 are the 12-bit temperature, and bit 2
+/// flags an open thermocouple circuit.
+fn decode_word(word: u16) -> Result<f32, PanelError> {
+    if word & 0b0100 != 0 {
+        return Err(PanelError::ThermocoupleOpen);
+    }
+
+    let raw = (word >> 3) & 0x0FFF;
+    Ok(raw as f32 * 0.25)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_zero_degrees() {
+        assert_eq!(decode_word(0x0000).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn decodes_positive_temperature() {
+        // 12-bit raw value 400 (0x190) in bits 14..=3 -> 100.0C.
+        let word = 400u16 << 3;
+        assert_eq!(decode_word(word).unwrap(), 100.0);
+    }
+
+    #[test]
+    fn open_circuit_bit_is_reported_as_error() {
+        let word = 0b0100;
+        assert!(matches!(decode_word(word), Err(PanelError::ThermocoupleOpen)));
+    }
+
+    #[test]
+    fn dummy_bit_is_ignored() {
+        // Bit 15 set shouldn't affect the decoded temperature.
+        let word = (1u16 << 15) | (400u16 << 3);
+        assert_eq!(decode_word(word).unwrap(), 100.0);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod controller;
+pub mod graph;
+pub mod heater;
+pub mod max6675;
+pub mod pid;
+
+pub use controller::ReflowController;
+pub use graph::TempGraph;
+pub use heater::Heater;
+pub use max6675::Max6675;
+pub use pid::Pid;
@@ -0,0 +1,76 @@
+/// A textbook PID controller producing a 0..=100% heater duty cycle.
+pub struct Pid {
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    integral: f32,
+    prev_error: f32,
+    output_limit: (f32, f32),
+}
+
+impl Pid {
+    pub fn new(kp: f32, ki: f32, kd: f32) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral: 0.0,
+            prev_error: 0.0,
+            output_limit: (0.0, 100.0),
+        }
+    }
+
+    /// Compute the next duty cycle for a `setpoint`/`measured` pair, `dt`
+    /// seconds since the previous step.
+    pub fn step(&mut self, setpoint: f32, measured: f32, dt: f32) -> f32 {
+        let (min, max) = self.output_limit;
+        let error = setpoint - measured;
+
+        // Anti-windup: keep the integral term itself within the range that
+        // could possibly contribute to the output, rather than letting it
+        // grow unbounded while the output is already saturated.
+        self.integral += error * dt;
+        if self.ki.abs() > f32::EPSILON {
+            let (lo, hi) = (min / self.ki, max / self.ki);
+            self.integral = self.integral.clamp(lo.min(hi), lo.max(hi));
+        }
+
+        let derivative = (error - self.prev_error) / dt;
+        self.prev_error = error;
+
+        let output = self.kp * error + self.ki * self.integral + self.kd * derivative;
+        output.clamp(min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proportional_only_tracks_error() {
+        let mut pid = Pid::new(2.0, 0.0, 0.0);
+        // error = 10, kp = 2 -> 20% duty.
+        assert_eq!(pid.step(100.0, 90.0, 1.0), 20.0);
+    }
+
+    #[test]
+    fn output_is_clamped_to_0_100() {
+        let mut pid = Pid::new(50.0, 0.0, 0.0);
+        assert_eq!(pid.step(1000.0, 0.0, 1.0), 100.0);
+        assert_eq!(pid.step(0.0, 1000.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn integral_windup_is_clamped() {
+        let mut pid = Pid::new(0.0, 10.0, 0.0);
+        // Each step adds error * dt = 100 to the integral; ki = 10 would
+        // push the output far past 100% without anti-windup clamping.
+        for _ in 0..10 {
+            let duty = pid.step(100.0, 0.0, 1.0);
+            assert!((0.0..=100.0).contains(&duty));
+        }
+        // Integral should have saturated at max / ki, not kept growing.
+        assert_eq!(pid.integral, 10.0);
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use gpiocdev::line::{Bias, EdgeDetection};
+use gpiocdev::request::Request;
+
+use crate::error::PanelError;
+
+/// An edge arriving within this long of the last accepted edge on the same
+/// line is treated as switch bounce and dropped.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A physical button on the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Button {
+    Up,
+    Down,
+    Select,
+    Back,
+}
+
+/// GPIO line offsets (on `chip`) wired to each button.
+pub struct ButtonPins {
+    pub chip: &'static str,
+    pub up: u32,
+    pub down: u32,
+    pub select: u32,
+    pub back: u32,
+}
+
+/// Reads debounced button presses off a set of GPIO input lines.
+///
+/// Lines are requested with `Bias::PullUp` and `EdgeDetection::FallingEdge`:
+/// a momentary button to ground sits HIGH at idle (pulled up) and pulls the
+/// line LOW on press, so the falling edge is the press itself.
+pub struct ButtonReader {
+    request: Request,
+    offsets: HashMap<u32, Button>,
+    last_accepted: HashMap<u32, Instant>,
+}
+
+impl ButtonReader {
+    pub fn new(pins: ButtonPins) -> Result<Self, PanelError> {
+        let offsets = HashMap::from([
+            (pins.up, Button::Up),
+            (pins.down, Button::Down),
+            (pins.select, Button::Select),
+            (pins.back, Button::Back),
+        ]);
+
+        let request = Request::builder()
+            .on_chip(pins.chip)
+            .with_lines(&[pins.up, pins.down, pins.select, pins.back])
+            .with_bias(Bias::PullUp)
+            .with_edge_detection(EdgeDetection::FallingEdge)
+            .request()
+            .map_err(|e| PanelError::Gpiocdev(e.to_string()))?;
+
+        Ok(Self {
+            request,
+            offsets,
+            last_accepted: HashMap::new(),
+        })
+    }
+
+    /// Block until the next debounced button press and return it.
+    pub fn next_event(&mut self) -> Result<Button, PanelError> {
+        loop {
+            let event = self
+                .request
+                .read_edge_event()
+                .map_err(|e| PanelError::Gpiocdev(e.to_string()))?;
+            if let Some(button) = self.accept(event.offset) {
+                return Ok(button);
+            }
+        }
+    }
+
+    /// Wait up to `timeout` for the next debounced button press. Returns
+    /// `Ok(None)` if `timeout` elapses with nothing accepted; `timeout` of
+    /// `None` blocks indefinitely, same as [`Self::next_event`].
+    pub fn poll_event(&mut self, timeout: Option<Duration>) -> Result<Option<Button>, PanelError> {
+        let Some(mut remaining) = timeout else {
+            return self.next_event().map(Some);
+        };
+
+        loop {
+            let started = Instant::now();
+            let ready = self
+                .request
+                .wait_edge_event(remaining)
+                .map_err(|e| PanelError::Gpiocdev(e.to_string()))?;
+            if !ready {
+                return Ok(None);
+            }
+
+            let event = self
+                .request
+                .read_edge_event()
+                .map_err(|e| PanelError::Gpiocdev(e.to_string()))?;
+            if let Some(button) = self.accept(event.offset) {
+                return Ok(Some(button));
+            }
+
+            // Bounced edge consumed part of the budget; wait out the rest.
+            remaining = remaining.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Apply debounce and offset-to-button lookup to a raw edge event.
+    fn accept(&mut self, offset: u32) -> Option<Button> {
+        let now = Instant::now();
+        let bounced = self
+            .last_accepted
+            .get(&offset)
+            .is_some_and(|last| now.duration_since(*last) < DEBOUNCE);
+        if bounced {
+            return None;
+        }
+        self.last_accepted.insert(offset, now);
+        self.offsets.get(&offset).copied()
+    }
+}
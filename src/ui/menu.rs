@@ -0,0 +1,134 @@
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+use crate::ui::button::{Button, ButtonReader};
+use crate::ui::scene::Scene;
+
+const ROW_HEIGHT: i32 = 12;
+
+/// Drives a list of [`Scene`]s from physical buttons.
+///
+/// With no scene active, renders the scene names as a list with the
+/// selected row highlighted (its background and text colours inverted).
+/// `Select` enters that scene full-screen; `Back` returns to the list.
+pub struct Menu<P: Panel> {
+    scenes: Vec<Box<dyn Scene<P>>>,
+    selected: usize,
+    active: bool,
+    fg: P::Color,
+    bg: P::Color,
+}
+
+impl<P: Panel> Menu<P> {
+    pub fn new(scenes: Vec<Box<dyn Scene<P>>>, fg: P::Color, bg: P::Color) -> Self {
+        assert!(!scenes.is_empty(), "menu needs at least one scene");
+        Self {
+            scenes,
+            selected: 0,
+            active: false,
+            fg,
+            bg,
+        }
+    }
+
+    /// Render the current state (list or active scene) to `target`.
+    pub fn render(&mut self, target: &mut P) -> Result<(), PanelError> {
+        target.clear(self.bg)?;
+
+        if self.active {
+            return self.scenes[self.selected].render(target);
+        }
+
+        let normal = MonoTextStyle::new(&FONT_6X10, self.fg);
+        let inverted = MonoTextStyle::new(&FONT_6X10, self.bg);
+
+        for (i, scene) in self.scenes.iter().enumerate() {
+            let y = i as i32 * ROW_HEIGHT;
+            if i == self.selected {
+                let width = target.bounding_box().size.width;
+                Rectangle::new(Point::new(0, y), Size::new(width, ROW_HEIGHT as u32))
+                    .into_styled(PrimitiveStyle::with_fill(self.fg))
+                    .draw(target)?;
+                Text::with_baseline(scene.name(), Point::new(2, y + 1), inverted, Baseline::Top)
+                    .draw(target)?;
+            } else {
+                Text::with_baseline(scene.name(), Point::new(2, y + 1), normal, Baseline::Top)
+                    .draw(target)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a button press, returning whether the display needs a redraw.
+    ///
+    /// While a scene is active, every button other than `Back` is passed to
+    /// that scene and always reports a redraw, whether or not the scene's
+    /// content changed.
+    pub fn on_input(&mut self, button: Button) -> bool {
+        if self.active {
+            match button {
+                Button::Back => {
+                    self.active = false;
+                    true
+                }
+                other => {
+                    self.scenes[self.selected].on_input(other);
+                    true
+                }
+            }
+        } else {
+            match button {
+                Button::Up => {
+                    self.selected = self.selected.checked_sub(1).unwrap_or(self.scenes.len() - 1);
+                    true
+                }
+                Button::Down => {
+                    self.selected = (self.selected + 1) % self.scenes.len();
+                    true
+                }
+                Button::Select => {
+                    self.active = true;
+                    true
+                }
+                Button::Back => false,
+            }
+        }
+    }
+
+    /// Drive the menu forever, redrawing (and flushing) only when
+    /// [`Self::on_input`] reports a button press needs it or the active
+    /// scene's own [`Scene::poll`] says it needs a redraw (e.g. a clock
+    /// waiting for the next minute).
+    pub fn run(&mut self, target: &mut P, buttons: &mut ButtonReader) -> Result<(), PanelError> {
+        self.render(target)?;
+        target.flush()?;
+
+        loop {
+            let timeout = self
+                .active
+                .then(|| self.scenes[self.selected].poll_interval())
+                .flatten();
+
+            match buttons.poll_event(timeout)? {
+                Some(button) => {
+                    if self.on_input(button) {
+                        self.render(target)?;
+                        target.flush()?;
+                    }
+                }
+                None => {
+                    if self.active && self.scenes[self.selected].poll() {
+                        self.render(target)?;
+                        target.flush()?;
+                    }
+                }
+            }
+        }
+    }
+}
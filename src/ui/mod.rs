@@ -0,0 +1,7 @@
+pub mod button;
+pub mod menu;
+pub mod scene;
+
+pub use button::{Button, ButtonPins, ButtonReader};
+pub use menu::Menu;
+pub use scene::Scene;
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use crate::error::PanelError;
+use crate::panel::Panel;
+use crate::ui::button::Button;
+
+/// One screen of the menu-driven UI.
+///
+/// Implementors are both a selectable menu entry (via [`Scene::name`]) and
+/// the full-screen content shown once that entry is active.
+pub trait Scene<P: Panel> {
+    /// Label shown for this entry in the menu list.
+    fn name(&self) -> &str;
+
+    /// Render this scene's content into `target`.
+    fn render(&mut self, target: &mut P) -> Result<(), PanelError>;
+
+    /// Handle a button press while this scene is active.
+    fn on_input(&mut self, button: Button);
+
+    /// How long `Menu::run` should wait for a button press before calling
+    /// [`Scene::poll`] instead, while this scene is active. `None` (the
+    /// default) means this scene never needs an unprompted redraw, so the
+    /// menu just blocks on input.
+    fn poll_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called when `poll_interval` elapses with no button press. Return
+    /// `true` if the scene's content changed and the display should be
+    /// redrawn; the default never does.
+    fn poll(&mut self) -> bool {
+        false
+    }
+}